@@ -1,13 +1,354 @@
+mod op;
+mod pack;
 mod spaces;
 
+use std::fmt;
+
+pub use op::Op;
+pub use pack::{Pack, PackError};
+pub use spaces::{Container, ContainerSpace, EmptySpace};
+
+#[cfg(test)]
 use spaces::Space;
 
 /// Contains a board to play on
 ///
 /// # Arguments
 ///
-/// * `T` - What is contained on the spaces of the board
+/// * `S` - The type of space used on the board, implementing `Container<T>`
+/// * `T` - The type of element the board's spaces hold, used by the move
+///   history to record and invert operations
 /// * `SIZE` - The number of rows and columns in the board
-struct Board<'a, T: Copy, const SIZE: usize> {
-	matrix: [[&'a dyn Space<T>; SIZE]; SIZE],
+#[derive(Debug)]
+pub struct Board<S, T, const SIZE: usize> {
+	matrix: [[S; SIZE]; SIZE],
+	undo_stack: Vec<Op<T>>,
+	redo_stack: Vec<Op<T>>,
+}
+
+impl<S, T, const SIZE: usize> Board<S, T, SIZE> {
+	/// Gets the space at the given row and column
+	pub fn get(&self, row: usize, col: usize) -> &S {
+		&self.matrix[row][col]
+	}
+}
+
+impl<S: Container<T>, T: Copy + PartialEq + fmt::Debug, const SIZE: usize> Board<S, T, SIZE> {
+	/// Performs the forward mutation described by `op`, without touching the
+	/// history stacks
+	fn perform(&mut self, op: Op<T>) {
+		match op {
+			Op::Place { row, col, element } => {
+				self.matrix[row][col].set_optional_element(Some(element));
+			}
+			Op::Remove { row, col, .. } => {
+				self.matrix[row][col].clear();
+			}
+			Op::Move { from, to, captured } => {
+				debug_assert_eq!(
+					self.matrix[to.0][to.1].as_option(),
+					&captured,
+					"Op::Move's captured element did not match the board state at `to`"
+				);
+				let element = *self.matrix[from.0][from.1].as_option();
+				self.matrix[from.0][from.1].clear();
+				self.matrix[to.0][to.1].set_optional_element(element);
+			}
+		}
+	}
+
+	/// Applies `op` to the board, pushing it onto the undo history and
+	/// clearing the redo history, which the new edit makes stale
+	pub fn apply(&mut self, op: Op<T>) {
+		self.perform(op);
+		self.undo_stack.push(op);
+		self.redo_stack.clear();
+	}
+
+	/// Reverts the most recently applied op, if any, moving it onto the redo history
+	pub fn undo(&mut self) {
+		if let Some(op) = self.undo_stack.pop() {
+			match op {
+				Op::Place { row, col, element } => {
+					debug_assert_eq!(
+						self.matrix[row][col].as_option(),
+						&Some(element),
+						"Op::Place's element did not match the board state being undone"
+					);
+					self.matrix[row][col].clear();
+				}
+				Op::Remove { row, col, previous } => {
+					debug_assert!(
+						self.matrix[row][col].is_empty(),
+						"Op::Remove's space was not empty when undoing the removal"
+					);
+					self.matrix[row][col].set_optional_element(Some(previous));
+				}
+				Op::Move { from, to, captured } => {
+					let element = *self.matrix[to.0][to.1].as_option();
+					self.matrix[to.0][to.1].set_optional_element(captured);
+					self.matrix[from.0][from.1].set_optional_element(element);
+				}
+			}
+			self.redo_stack.push(op);
+		}
+	}
+
+	/// Re-applies the most recently undone op, if any, moving it back onto the undo history
+	pub fn redo(&mut self) {
+		if let Some(op) = self.redo_stack.pop() {
+			self.perform(op);
+			self.undo_stack.push(op);
+		}
+	}
+}
+
+impl<S: Pack, T, const SIZE: usize> Pack for Board<S, T, SIZE> {
+	const LEN: usize = SIZE * SIZE * S::LEN;
+
+	fn pack_into_slice(&self, dst: &mut [u8]) {
+		// Spaces are packed by their index rather than via `chunks`/`chunks_mut`,
+		// since those panic on a zero-sized chunk, which `S::LEN` can be.
+		for row in 0..SIZE {
+			for col in 0..SIZE {
+				let start = (row * SIZE + col) * S::LEN;
+				self.matrix[row][col].pack_into_slice(&mut dst[start..start + S::LEN]);
+			}
+		}
+	}
+
+	fn unpack_from_slice(src: &[u8]) -> Result<Self, PackError> {
+		if src.len() != Self::LEN {
+			return Err(PackError::InvalidLength {
+				expected: Self::LEN,
+				actual: src.len(),
+			});
+		}
+
+		let mut rows = Vec::with_capacity(SIZE);
+		for row in 0..SIZE {
+			let mut spaces = Vec::with_capacity(SIZE);
+			for col in 0..SIZE {
+				let start = (row * SIZE + col) * S::LEN;
+				spaces.push(S::unpack_from_slice(&src[start..start + S::LEN])?);
+			}
+			rows.push(spaces.try_into().unwrap_or_else(|_: Vec<S>| unreachable!()));
+		}
+
+		Ok(Board {
+			matrix: rows.try_into().unwrap_or_else(|_: Vec<[S; SIZE]>| unreachable!()),
+			undo_stack: Vec::new(),
+			redo_stack: Vec::new(),
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	fn board_with(
+		matrix: [[ContainerSpace<u8>; 2]; 2],
+	) -> Board<ContainerSpace<u8>, u8, 2> {
+		Board {
+			matrix,
+			undo_stack: Vec::new(),
+			redo_stack: Vec::new(),
+		}
+	}
+
+	#[test]
+	fn pack_len() {
+		assert_eq!(Board::<ContainerSpace<u8>, u8, 2>::LEN, 8);
+	}
+
+	#[test]
+	fn pack_into_slice() {
+		let cut = board_with([
+			[ContainerSpace::with_element(1u8), ContainerSpace::new()],
+			[ContainerSpace::new(), ContainerSpace::with_element(2u8)],
+		]);
+		let mut dst = [0u8; 8];
+		cut.pack_into_slice(&mut dst);
+		assert_eq!(dst, [1, 1, 0, 0, 0, 0, 1, 2]);
+	}
+
+	#[test]
+	fn unpack_from_slice() {
+		let src = [1, 1, 0, 0, 0, 0, 1, 2];
+		let cut = Board::<ContainerSpace<u8>, u8, 2>::unpack_from_slice(&src).unwrap();
+		assert_eq!(cut.matrix[0][0].as_option(), &Some(1));
+		assert_eq!(cut.matrix[0][1].as_option(), &None);
+		assert_eq!(cut.matrix[1][0].as_option(), &None);
+		assert_eq!(cut.matrix[1][1].as_option(), &Some(2));
+	}
+
+	#[test]
+	fn unpack_from_slice_invalid_length() {
+		let err = Board::<ContainerSpace<u8>, u8, 2>::unpack_from_slice(&[0u8; 7]).unwrap_err();
+		assert_eq!(
+			err,
+			PackError::InvalidLength {
+				expected: 8,
+				actual: 7
+			}
+		);
+	}
+
+	#[test]
+	fn pack_into_slice_does_not_panic_on_zero_length_space() {
+		let cut = Board::<EmptySpace, u8, 2> {
+			matrix: [[EmptySpace::default(); 2]; 2],
+			undo_stack: Vec::new(),
+			redo_stack: Vec::new(),
+		};
+		let mut dst: [u8; 0] = [];
+		cut.pack_into_slice(&mut dst);
+	}
+
+	#[test]
+	fn unpack_from_slice_does_not_panic_on_zero_length_space() {
+		assert!(Board::<EmptySpace, u8, 2>::unpack_from_slice(&[]).is_ok());
+	}
+
+	#[test]
+	fn apply_place() {
+		let mut cut = board_with([
+			[ContainerSpace::new(), ContainerSpace::new()],
+			[ContainerSpace::new(), ContainerSpace::new()],
+		]);
+		cut.apply(Op::Place {
+			row: 0,
+			col: 0,
+			element: 5u8,
+		});
+		assert_eq!(cut.matrix[0][0].as_option(), &Some(5));
+		assert_eq!(cut.undo_stack.len(), 1);
+	}
+
+	#[test]
+	fn apply_remove() {
+		let mut cut = board_with([
+			[ContainerSpace::with_element(5u8), ContainerSpace::new()],
+			[ContainerSpace::new(), ContainerSpace::new()],
+		]);
+		cut.apply(Op::Remove {
+			row: 0,
+			col: 0,
+			previous: 5,
+		});
+		assert_eq!(cut.matrix[0][0].as_option(), &None);
+	}
+
+	#[test]
+	fn apply_move() {
+		let mut cut = board_with([
+			[ContainerSpace::with_element(5u8), ContainerSpace::new()],
+			[ContainerSpace::new(), ContainerSpace::new()],
+		]);
+		cut.apply(Op::Move {
+			from: (0, 0),
+			to: (1, 1),
+			captured: None,
+		});
+		assert_eq!(cut.matrix[0][0].as_option(), &None);
+		assert_eq!(cut.matrix[1][1].as_option(), &Some(5));
+	}
+
+	#[test]
+	fn undo_place() {
+		let mut cut = board_with([
+			[ContainerSpace::new(), ContainerSpace::new()],
+			[ContainerSpace::new(), ContainerSpace::new()],
+		]);
+		cut.apply(Op::Place {
+			row: 0,
+			col: 0,
+			element: 5u8,
+		});
+		cut.undo();
+		assert_eq!(cut.matrix[0][0].as_option(), &None);
+		assert_eq!(cut.undo_stack.len(), 0);
+		assert_eq!(cut.redo_stack.len(), 1);
+	}
+
+	#[test]
+	fn undo_remove() {
+		let mut cut = board_with([
+			[ContainerSpace::with_element(5u8), ContainerSpace::new()],
+			[ContainerSpace::new(), ContainerSpace::new()],
+		]);
+		cut.apply(Op::Remove {
+			row: 0,
+			col: 0,
+			previous: 5,
+		});
+		cut.undo();
+		assert_eq!(cut.matrix[0][0].as_option(), &Some(5));
+	}
+
+	#[test]
+	fn undo_move_with_capture() {
+		let mut cut = board_with([
+			[ContainerSpace::with_element(5u8), ContainerSpace::new()],
+			[ContainerSpace::new(), ContainerSpace::with_element(9u8)],
+		]);
+		cut.apply(Op::Move {
+			from: (0, 0),
+			to: (1, 1),
+			captured: Some(9),
+		});
+		cut.undo();
+		assert_eq!(cut.matrix[0][0].as_option(), &Some(5));
+		assert_eq!(cut.matrix[1][1].as_option(), &Some(9));
+	}
+
+	#[test]
+	fn undo_on_empty_history_does_nothing() {
+		let mut cut = board_with([
+			[ContainerSpace::with_element(5u8), ContainerSpace::new()],
+			[ContainerSpace::new(), ContainerSpace::new()],
+		]);
+		cut.undo();
+		assert_eq!(cut.matrix[0][0].as_option(), &Some(5));
+	}
+
+	#[test]
+	fn redo_replays_undone_op() {
+		let mut cut = board_with([
+			[ContainerSpace::new(), ContainerSpace::new()],
+			[ContainerSpace::new(), ContainerSpace::new()],
+		]);
+		cut.apply(Op::Place {
+			row: 0,
+			col: 0,
+			element: 5u8,
+		});
+		cut.undo();
+		cut.redo();
+		assert_eq!(cut.matrix[0][0].as_option(), &Some(5));
+		assert_eq!(cut.undo_stack.len(), 1);
+		assert_eq!(cut.redo_stack.len(), 0);
+	}
+
+	#[test]
+	fn apply_after_undo_clears_redo_history() {
+		let mut cut = board_with([
+			[ContainerSpace::new(), ContainerSpace::new()],
+			[ContainerSpace::new(), ContainerSpace::new()],
+		]);
+		cut.apply(Op::Place {
+			row: 0,
+			col: 0,
+			element: 5u8,
+		});
+		cut.undo();
+		cut.apply(Op::Place {
+			row: 0,
+			col: 1,
+			element: 6u8,
+		});
+		assert_eq!(cut.redo_stack.len(), 0);
+	}
 }
@@ -0,0 +1,25 @@
+/// A reversible mutation applied to a board's spaces, recording enough prior
+/// state that it can be undone
+///
+/// # Arguments
+///
+/// * `T` - The type of element placed, removed, or moved by the operation
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Op<T> {
+	/// Places `element` into the space at `(row, col)`, which must be empty
+	Place { row: usize, col: usize, element: T },
+	/// Removes the element at `(row, col)`, recording it as `previous` so the
+	/// removal can be undone
+	Remove {
+		row: usize,
+		col: usize,
+		previous: T,
+	},
+	/// Moves the element at `from` onto `to`, capturing whatever element was
+	/// already at `to`
+	Move {
+		from: (usize, usize),
+		to: (usize, usize),
+		captured: Option<T>,
+	},
+}
@@ -0,0 +1,113 @@
+use std::error::Error;
+use std::fmt;
+
+/// An error while unpacking a value from its packed byte representation
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PackError {
+	/// The slice did not have the length expected for the value being unpacked
+	InvalidLength {
+		/// The number of bytes the value expects
+		expected: usize,
+		/// The number of bytes actually given
+		actual: usize,
+	},
+	/// A tag byte held a value other than 0 or 1
+	InvalidTag(u8),
+}
+
+impl fmt::Display for PackError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			PackError::InvalidLength { expected, actual } => write!(
+				f,
+				"expected a slice of length {expected}, but got one of length {actual}"
+			),
+			PackError::InvalidTag(tag) => write!(f, "expected a tag of 0 or 1, but got {tag}"),
+		}
+	}
+}
+
+impl Error for PackError {}
+
+/// Serializes a value into a compact, fixed-length byte layout, and back again
+pub trait Pack: Sized {
+	/// The number of bytes needed to pack a value of this type
+	const LEN: usize;
+
+	/// Packs this value into `dst`, which must be exactly `LEN` bytes long
+	fn pack_into_slice(&self, dst: &mut [u8]);
+
+	/// Unpacks a value from `src`, which must be exactly `LEN` bytes long
+	fn unpack_from_slice(src: &[u8]) -> Result<Self, PackError>;
+}
+
+impl Pack for u8 {
+	const LEN: usize = 1;
+
+	fn pack_into_slice(&self, dst: &mut [u8]) {
+		dst[0] = *self;
+	}
+
+	fn unpack_from_slice(src: &[u8]) -> Result<Self, PackError> {
+		match src {
+			[byte] => Ok(*byte),
+			_ => Err(PackError::InvalidLength {
+				expected: 1,
+				actual: src.len(),
+			}),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn invalid_length_to_string() {
+		let err = PackError::InvalidLength {
+			expected: 2,
+			actual: 3,
+		};
+		assert_eq!(
+			err.to_string(),
+			"expected a slice of length 2, but got one of length 3"
+		);
+	}
+
+	#[test]
+	fn invalid_tag_to_string() {
+		let err = PackError::InvalidTag(7);
+		assert_eq!(err.to_string(), "expected a tag of 0 or 1, but got 7");
+	}
+
+	#[test]
+	fn u8_pack_len() {
+		assert_eq!(u8::LEN, 1);
+	}
+
+	#[test]
+	fn u8_pack_into_slice() {
+		let mut dst = [0u8; 1];
+		5u8.pack_into_slice(&mut dst);
+		assert_eq!(dst, [5]);
+	}
+
+	#[test]
+	fn u8_unpack_from_slice() {
+		assert_eq!(u8::unpack_from_slice(&[5]).unwrap(), 5);
+	}
+
+	#[test]
+	fn u8_unpack_from_slice_invalid_length() {
+		let err = u8::unpack_from_slice(&[5, 6]).unwrap_err();
+		assert_eq!(
+			err,
+			PackError::InvalidLength {
+				expected: 1,
+				actual: 2
+			}
+		);
+	}
+}
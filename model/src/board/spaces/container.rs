@@ -4,7 +4,8 @@ use std::fmt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use super::Space;
+use super::super::pack::{Pack, PackError};
+use super::{Container, Space};
 
 /// There is no element at the given space
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
@@ -84,6 +85,48 @@ impl<T> ContainerSpace<T> {
 	pub fn clear(&mut self) {
 		self.set_optional_element(None);
 	}
+
+	/// Removes the element from the space, returning it, and leaving the space empty
+	pub fn take(&mut self) -> Option<T> {
+		self.element.take()
+	}
+
+	/// Sets the element of the space, returning the previous element
+	pub fn replace(&mut self, element: T) -> Option<T> {
+		self.element.replace(element)
+	}
+
+	/// Inserts the element into the space if it is empty, then returns a mutable
+	/// reference to the element
+	pub fn get_or_insert(&mut self, element: T) -> &mut T {
+		self.element.get_or_insert(element)
+	}
+
+	/// Maps a `ContainerSpace<T>` to a `ContainerSpace<U>` by applying a function
+	/// to the contained element, if there is one
+	pub fn map<U>(&self, f: impl FnOnce(&T) -> U) -> ContainerSpace<U> {
+		ContainerSpace {
+			element: self.element.as_ref().map(f),
+		}
+	}
+
+	/// Maps a `ContainerSpace<T>` to a `ContainerSpace<U>` by applying a function
+	/// to the contained element, if there is one, and flattening the result
+	pub fn and_then<U>(&self, f: impl FnOnce(&T) -> Option<U>) -> ContainerSpace<U> {
+		ContainerSpace {
+			element: self.element.as_ref().and_then(f),
+		}
+	}
+}
+
+impl<T: Clone> ContainerSpace<T> {
+	/// Returns an empty space unless the element satisfies the predicate, in
+	/// which case the element is kept
+	pub fn filter<F: Fn(&T) -> bool>(&self, predicate: F) -> Self {
+		ContainerSpace {
+			element: self.element.clone().filter(|element| predicate(element)),
+		}
+	}
 }
 
 impl<T: fmt::Display> fmt::Display for ContainerSpace<T> {
@@ -104,6 +147,60 @@ impl<T> From<Option<T>> for ContainerSpace<T> {
 	}
 }
 
+impl<T> Container<T> for ContainerSpace<T> {
+	fn is_empty(&self) -> bool {
+		ContainerSpace::is_empty(self)
+	}
+
+	fn has_element(&self) -> bool {
+		ContainerSpace::has_element(self)
+	}
+
+	fn as_option(&self) -> &Option<T> {
+		ContainerSpace::as_option(self)
+	}
+
+	fn set_optional_element(&mut self, option: Option<T>) {
+		ContainerSpace::set_optional_element(self, option)
+	}
+
+	fn clear(&mut self) {
+		ContainerSpace::clear(self)
+	}
+}
+
+impl<T: Pack> Pack for ContainerSpace<T> {
+	const LEN: usize = 1 + T::LEN;
+
+	fn pack_into_slice(&self, dst: &mut [u8]) {
+		match self.as_option() {
+			Some(element) => {
+				dst[0] = 1;
+				element.pack_into_slice(&mut dst[1..]);
+			}
+			None => {
+				dst[0] = 0;
+				dst[1..].fill(0);
+			}
+		}
+	}
+
+	fn unpack_from_slice(src: &[u8]) -> Result<Self, PackError> {
+		if src.len() != Self::LEN {
+			return Err(PackError::InvalidLength {
+				expected: Self::LEN,
+				actual: src.len(),
+			});
+		}
+
+		match src[0] {
+			0 => Ok(ContainerSpace::new()),
+			1 => Ok(ContainerSpace::with_element(T::unpack_from_slice(&src[1..])?)),
+			tag => Err(PackError::InvalidTag(tag)),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -242,4 +339,187 @@ mod tests {
 		let cut = ContainerSpace::from(element);
 		assert_eq!(cut.element, element);
 	}
+
+	#[test]
+	fn container_is_empty() {
+		let cut = ContainerSpace::<u8>::new();
+		assert!(Container::is_empty(&cut));
+
+		let cut = ContainerSpace::with_element(5u8);
+		assert!(!Container::is_empty(&cut));
+	}
+
+	#[test]
+	fn container_has_element() {
+		let cut = ContainerSpace::<u8>::new();
+		assert!(!Container::has_element(&cut));
+
+		let cut = ContainerSpace::with_element(5u8);
+		assert!(Container::has_element(&cut));
+	}
+
+	#[test]
+	fn container_as_option() {
+		let cut = ContainerSpace::with_element(5u8);
+		assert_eq!(Container::as_option(&cut), &Some(5));
+	}
+
+	#[test]
+	fn container_set_optional_element() {
+		let mut cut = ContainerSpace::<u8>::new();
+		Container::set_optional_element(&mut cut, Some(5));
+		assert_eq!(cut.element, Some(5));
+	}
+
+	#[test]
+	fn container_clear() {
+		let mut cut = ContainerSpace::with_element(5u8);
+		Container::clear(&mut cut);
+		assert_eq!(cut.element, None);
+	}
+
+	#[test]
+	fn insert_if_empty() {
+		// test when empty
+		let mut cut = ContainerSpace::<u8>::new();
+		assert!(cut.insert_if_empty(|| 5));
+		assert_eq!(cut.element, Some(5));
+
+		// test when occupied
+		let mut cut = ContainerSpace::with_element(5u8);
+		assert!(!cut.insert_if_empty(|| 6));
+		assert_eq!(cut.element, Some(5));
+	}
+
+	#[test]
+	fn pack_len() {
+		assert_eq!(ContainerSpace::<u8>::LEN, 2);
+	}
+
+	#[test]
+	fn pack_into_slice_with_element() {
+		let cut = ContainerSpace::with_element(5u8);
+		let mut dst = [0u8; 2];
+		cut.pack_into_slice(&mut dst);
+		assert_eq!(dst, [1, 5]);
+	}
+
+	#[test]
+	fn pack_into_slice_empty() {
+		let cut = ContainerSpace::<u8>::new();
+		let mut dst = [0xFFu8; 2];
+		cut.pack_into_slice(&mut dst);
+		assert_eq!(dst, [0, 0]);
+	}
+
+	#[test]
+	fn unpack_from_slice_with_element() {
+		let cut = ContainerSpace::<u8>::unpack_from_slice(&[1, 5]).unwrap();
+		assert_eq!(cut.element, Some(5));
+	}
+
+	#[test]
+	fn unpack_from_slice_empty() {
+		let cut = ContainerSpace::<u8>::unpack_from_slice(&[0, 0]).unwrap();
+		assert_eq!(cut.element, None);
+	}
+
+	#[test]
+	fn unpack_from_slice_invalid_tag() {
+		let err = ContainerSpace::<u8>::unpack_from_slice(&[2, 0]).unwrap_err();
+		assert_eq!(err, PackError::InvalidTag(2));
+	}
+
+	#[test]
+	fn unpack_from_slice_invalid_length() {
+		let err = ContainerSpace::<u8>::unpack_from_slice(&[0]).unwrap_err();
+		assert_eq!(
+			err,
+			PackError::InvalidLength {
+				expected: 2,
+				actual: 1
+			}
+		);
+	}
+
+	#[test]
+	fn take() {
+		// test with an element
+		let mut cut = ContainerSpace { element: Some(5) };
+		assert_eq!(cut.take(), Some(5));
+		assert_eq!(cut.element, None);
+
+		// test empty
+		let mut cut = ContainerSpace::<u8>::new();
+		assert_eq!(cut.take(), None);
+		assert_eq!(cut.element, None);
+	}
+
+	#[test]
+	fn replace() {
+		// test with an element
+		let mut cut = ContainerSpace { element: Some(5) };
+		assert_eq!(cut.replace(6), Some(5));
+		assert_eq!(cut.element, Some(6));
+
+		// test empty
+		let mut cut = ContainerSpace::<u8>::new();
+		assert_eq!(cut.replace(6), None);
+		assert_eq!(cut.element, Some(6));
+	}
+
+	#[test]
+	fn get_or_insert() {
+		// test with an element
+		let mut cut = ContainerSpace { element: Some(5) };
+		assert_eq!(*cut.get_or_insert(6), 5);
+
+		// test empty
+		let mut cut = ContainerSpace::<u8>::new();
+		assert_eq!(*cut.get_or_insert(6), 6);
+		assert_eq!(cut.element, Some(6));
+	}
+
+	#[test]
+	fn map() {
+		// test with an element
+		let cut = ContainerSpace { element: Some(5) };
+		assert_eq!(cut.map(|element| element + 1).element, Some(6));
+
+		// test empty
+		let cut = ContainerSpace::<u8>::new();
+		assert_eq!(cut.map(|element| element + 1).element, None);
+	}
+
+	#[test]
+	fn and_then() {
+		// test with an element
+		let cut = ContainerSpace { element: Some(5) };
+		assert_eq!(
+			cut.and_then(|element| if *element > 0 { Some(*element) } else { None }).element,
+			Some(5)
+		);
+
+		// test empty
+		let cut = ContainerSpace::<u8>::new();
+		assert_eq!(
+			cut.and_then(|element| if *element > 0 { Some(*element) } else { None }).element,
+			None
+		);
+	}
+
+	#[test]
+	fn filter() {
+		// test predicate satisfied
+		let cut = ContainerSpace { element: Some(5) };
+		assert_eq!(cut.filter(|&element| element > 0).element, Some(5));
+
+		// test predicate not satisfied
+		let cut = ContainerSpace { element: Some(5) };
+		assert_eq!(cut.filter(|&element| element > 10).element, None);
+
+		// test empty
+		let cut = ContainerSpace::<u8>::new();
+		assert_eq!(cut.filter(|&element| element > 0).element, None);
+	}
 }
@@ -4,7 +4,8 @@ use std::fmt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use super::Space;
+use super::super::pack::{Pack, PackError};
+use super::{Container, Space};
 
 /// A space in a board, doesn't contain an element
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
@@ -30,6 +31,45 @@ impl fmt::Display for EmptySpace {
     }
 }
 
+impl<T> Container<T> for EmptySpace {
+    fn is_empty(&self) -> bool {
+        true
+    }
+
+    fn has_element(&self) -> bool {
+        false
+    }
+
+    fn as_option(&self) -> &Option<T> {
+        &None
+    }
+
+    fn set_optional_element(&mut self, _option: Option<T>) {}
+
+    fn clear(&mut self) {}
+
+    fn insert_if_empty<F: FnOnce() -> T>(&mut self, _f: F) -> bool {
+        false
+    }
+}
+
+impl Pack for EmptySpace {
+    const LEN: usize = 0;
+
+    fn pack_into_slice(&self, _dst: &mut [u8]) {}
+
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, PackError> {
+        if !src.is_empty() {
+            return Err(PackError::InvalidLength {
+                expected: 0,
+                actual: src.len(),
+            });
+        }
+
+        Ok(EmptySpace::new())
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -51,4 +91,73 @@ mod tests {
         let cut = EmptySpace {};
         assert_eq!(cut.to_string(), "| |");
     }
+
+    #[test]
+    fn container_is_empty() {
+        let cut = EmptySpace::new();
+        assert!(Container::<u8>::is_empty(&cut));
+    }
+
+    #[test]
+    fn container_has_element() {
+        let cut = EmptySpace::new();
+        assert!(!Container::<u8>::has_element(&cut));
+    }
+
+    #[test]
+    fn container_as_option() {
+        let cut = EmptySpace::new();
+        assert_eq!(Container::<u8>::as_option(&cut), &None);
+    }
+
+    #[test]
+    fn container_set_optional_element_is_ignored() {
+        let mut cut = EmptySpace::new();
+        Container::set_optional_element(&mut cut, Some(5u8));
+        assert!(Container::<u8>::is_empty(&cut));
+    }
+
+    #[test]
+    fn container_clear_is_a_no_op() {
+        let mut cut = EmptySpace::new();
+        Container::<u8>::clear(&mut cut);
+        assert!(Container::<u8>::is_empty(&cut));
+    }
+
+    #[test]
+    fn insert_if_empty_is_ignored() {
+        let mut cut = EmptySpace::new();
+        assert!(!Container::insert_if_empty(&mut cut, || 5u8));
+        assert!(Container::<u8>::is_empty(&cut));
+    }
+
+    #[test]
+    fn pack_len() {
+        assert_eq!(EmptySpace::LEN, 0);
+    }
+
+    #[test]
+    fn pack_into_slice() {
+        let cut = EmptySpace::new();
+        let mut dst: [u8; 0] = [];
+        cut.pack_into_slice(&mut dst);
+    }
+
+    #[test]
+    fn unpack_from_slice() {
+        let cut = EmptySpace::unpack_from_slice(&[]).unwrap();
+        assert!(is::<EmptySpace>(&cut));
+    }
+
+    #[test]
+    fn unpack_from_slice_invalid_length() {
+        let err = EmptySpace::unpack_from_slice(&[0]).unwrap_err();
+        assert_eq!(
+            err,
+            PackError::InvalidLength {
+                expected: 0,
+                actual: 1
+            }
+        );
+    }
 }
@@ -11,6 +11,40 @@ pub use empty::EmptySpace;
 ///
 /// - `T` - The element the Space could contain
 pub trait Space<T: Sized>{
-    fn new() -> Self where Self: Sized; 
+    fn new() -> Self where Self: Sized;
+}
+
+/// Common behavior shared by every space type on a board, regardless of how
+/// it actually stores (or refuses to store) its element
+///
+/// # Arguments
+///
+/// - `T` - The element the container could hold
+pub trait Container<T> {
+    /// Checks if the space is empty or not
+    fn is_empty(&self) -> bool;
+
+    /// True if the space contains some element
+    fn has_element(&self) -> bool;
+
+    /// Gets the element from the space. Returns None if the space is empty
+    fn as_option(&self) -> &Option<T>;
+
+    /// Sets the element to the given piece, or clears it
+    fn set_optional_element(&mut self, option: Option<T>);
+
+    /// Clears the element from the space, making it empty
+    fn clear(&mut self);
+
+    /// Evaluates `f` and stores its result only if the space is currently
+    /// empty, returning whether the insertion happened
+    fn insert_if_empty<F: FnOnce() -> T>(&mut self, f: F) -> bool {
+        if self.is_empty() {
+            self.set_optional_element(Some(f()));
+            true
+        } else {
+            false
+        }
+    }
 }
 
@@ -0,0 +1,15 @@
+use crate::board::Board;
+
+/// Assigns a heuristic score to a leaf position, from the perspective of the
+/// side to move
+///
+/// # Arguments
+///
+/// * `S` - The type of space used on the board, implementing `Container<T>`
+/// * `T` - The type of element the board's spaces hold
+/// * `SIZE` - The number of rows and columns on the board
+pub trait Evaluator<S, T, const SIZE: usize> {
+	/// Scores `board` from the perspective of the side to move; larger is
+	/// better
+	fn evaluate(&self, board: &Board<S, T, SIZE>) -> i32;
+}
@@ -0,0 +1,14 @@
+use crate::board::{Board, Op};
+
+/// Produces every legal operation available to the side to move in a
+/// position
+///
+/// # Arguments
+///
+/// * `S` - The type of space used on the board, implementing `Container<T>`
+/// * `T` - The type of element the board's spaces hold
+/// * `SIZE` - The number of rows and columns on the board
+pub trait MoveGenerator<S, T, const SIZE: usize> {
+	/// Lists every legal op for the side to move in `board`
+	fn legal_ops(&self, board: &Board<S, T, SIZE>) -> Vec<Op<T>>;
+}
@@ -0,0 +1,241 @@
+mod evaluator;
+mod generator;
+mod transposition;
+
+pub use evaluator::Evaluator;
+pub use generator::MoveGenerator;
+pub use transposition::{Bound, Entry, TranspositionTable};
+
+use crate::board::{Board, Container, Op, Pack};
+
+/// A score no real evaluation can reach, used to seed alpha/beta without
+/// risking overflow when negated
+const NEG_INFINITY: i32 = i32::MIN + 1;
+const POS_INFINITY: i32 = i32::MAX;
+
+/// The result of a search: the best op found for the side to move, and its
+/// negamax score
+///
+/// # Arguments
+///
+/// * `T` - The type of element the board's spaces hold
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct BestMove<T> {
+	/// The best op found, or `None` if there were no legal ops to play
+	pub op: Option<Op<T>>,
+	/// The negamax score of `op` from the perspective of the side to move
+	pub score: i32,
+}
+
+/// Finds the best op for the side to move in `board`, searching `depth`
+/// plies with negamax and alpha-beta pruning
+///
+/// `generator` supplies the legal ops at each node and `evaluator` scores the
+/// leaves reached once `depth` is exhausted or no ops remain. `table` caches
+/// previously searched positions, keyed on their packed byte encoding, so
+/// that re-searches of a transposed position to an equal or shallower depth
+/// can reuse or tighten an earlier result. `board` is restored to its original state before this
+/// function returns; every op explored during the search is undone as soon
+/// as its subtree has been scored.
+pub fn search<S, T, const SIZE: usize>(
+	board: &mut Board<S, T, SIZE>,
+	depth: u32,
+	generator: &impl MoveGenerator<S, T, SIZE>,
+	evaluator: &impl Evaluator<S, T, SIZE>,
+	table: &mut TranspositionTable,
+) -> BestMove<T>
+where
+	S: Container<T> + Pack,
+	T: Copy + PartialEq + std::fmt::Debug,
+{
+	let ops = generator.legal_ops(board);
+	if ops.is_empty() {
+		return BestMove {
+			op: None,
+			score: evaluator.evaluate(board),
+		};
+	}
+
+	let mut best_op = None;
+	let mut best_score = NEG_INFINITY;
+	let mut alpha = NEG_INFINITY;
+
+	for op in ops {
+		board.apply(op);
+		let score = -negamax(board, depth.saturating_sub(1), -POS_INFINITY, -alpha, generator, evaluator, table);
+		board.undo();
+
+		if score > best_score {
+			best_score = score;
+			best_op = Some(op);
+		}
+		alpha = alpha.max(score);
+	}
+
+	BestMove {
+		op: best_op,
+		score: best_score,
+	}
+}
+
+/// Recursively scores `board` for the side to move, `depth` plies deep, by
+/// negamax with alpha-beta pruning over the ops `generator` supplies
+fn negamax<S, T, const SIZE: usize>(
+	board: &mut Board<S, T, SIZE>,
+	depth: u32,
+	mut alpha: i32,
+	mut beta: i32,
+	generator: &impl MoveGenerator<S, T, SIZE>,
+	evaluator: &impl Evaluator<S, T, SIZE>,
+	table: &mut TranspositionTable,
+) -> i32
+where
+	S: Container<T> + Pack,
+	T: Copy + PartialEq + std::fmt::Debug,
+{
+	let mut key = vec![0u8; Board::<S, T, SIZE>::LEN];
+	board.pack_into_slice(&mut key);
+
+	if let Some(entry) = table.get(&key) {
+		if entry.depth >= depth {
+			match entry.bound {
+				Bound::Exact => return entry.score,
+				Bound::Lower => alpha = alpha.max(entry.score),
+				Bound::Upper => beta = beta.min(entry.score),
+			}
+			if alpha >= beta {
+				return entry.score;
+			}
+		}
+	}
+
+	if depth == 0 {
+		return evaluator.evaluate(board);
+	}
+
+	let ops = generator.legal_ops(board);
+	if ops.is_empty() {
+		return evaluator.evaluate(board);
+	}
+
+	let original_alpha = alpha;
+	let mut best_score = NEG_INFINITY;
+
+	for op in ops {
+		board.apply(op);
+		let score = -negamax(board, depth - 1, -beta, -alpha, generator, evaluator, table);
+		board.undo();
+
+		best_score = best_score.max(score);
+		alpha = alpha.max(score);
+		if alpha >= beta {
+			break;
+		}
+	}
+
+	let bound = if best_score <= original_alpha {
+		Bound::Upper
+	} else if best_score >= beta {
+		Bound::Lower
+	} else {
+		Bound::Exact
+	};
+	table.insert(
+		key,
+		Entry {
+			depth,
+			score: best_score,
+			bound,
+		},
+	);
+
+	best_score
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::board::ContainerSpace;
+	use std::cell::Cell;
+
+	fn empty_board() -> Board<ContainerSpace<u8>, u8, 2> {
+		Board::unpack_from_slice(&[0u8; 8]).unwrap()
+	}
+
+	struct NoMoves;
+
+	impl MoveGenerator<ContainerSpace<u8>, u8, 2> for NoMoves {
+		fn legal_ops(&self, _board: &Board<ContainerSpace<u8>, u8, 2>) -> Vec<Op<u8>> {
+			Vec::new()
+		}
+	}
+
+	/// Offers `Op::Place { row: 0, col: 0, element: 1 }` exactly once, then
+	/// reports no further ops from any position
+	struct PlaceOnce(Cell<bool>);
+
+	impl PlaceOnce {
+		fn new() -> Self {
+			PlaceOnce(Cell::new(false))
+		}
+	}
+
+	impl MoveGenerator<ContainerSpace<u8>, u8, 2> for PlaceOnce {
+		fn legal_ops(&self, _board: &Board<ContainerSpace<u8>, u8, 2>) -> Vec<Op<u8>> {
+			if self.0.replace(true) {
+				Vec::new()
+			} else {
+				vec![Op::Place {
+					row: 0,
+					col: 0,
+					element: 1,
+				}]
+			}
+		}
+	}
+
+	struct PieceCount;
+
+	impl Evaluator<ContainerSpace<u8>, u8, 2> for PieceCount {
+		fn evaluate(&self, board: &Board<ContainerSpace<u8>, u8, 2>) -> i32 {
+			(0..2)
+				.flat_map(|row| (0..2).map(move |col| (row, col)))
+				.filter(|&(row, col)| board.get(row, col).has_element())
+				.count() as i32
+		}
+	}
+
+	#[test]
+	fn search_with_no_legal_ops_returns_leaf_evaluation() {
+		let mut board = empty_board();
+		let mut table = TranspositionTable::new();
+		let best = search(&mut board, 3, &NoMoves, &PieceCount, &mut table);
+		assert_eq!(best.op, None);
+		assert_eq!(best.score, 0);
+	}
+
+	#[test]
+	fn search_picks_the_only_available_op() {
+		let mut board = empty_board();
+		let mut table = TranspositionTable::new();
+		let best = search(&mut board, 2, &PlaceOnce::new(), &PieceCount, &mut table);
+		assert_eq!(
+			best.op,
+			Some(Op::Place {
+				row: 0,
+				col: 0,
+				element: 1,
+			})
+		);
+		assert_eq!(best.score, -1);
+	}
+
+	#[test]
+	fn search_restores_the_board_after_exploring_every_branch() {
+		let mut board = empty_board();
+		let mut table = TranspositionTable::new();
+		search(&mut board, 2, &PlaceOnce::new(), &PieceCount, &mut table);
+		assert!(board.get(0, 0).is_empty());
+	}
+}
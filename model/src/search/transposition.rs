@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+/// Distinguishes how a transposition table entry's stored score relates to
+/// the true value of the node it was computed for
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Bound {
+	/// `score` is the node's exact value
+	Exact,
+	/// `score` is a lower bound, recorded after a beta cutoff
+	Lower,
+	/// `score` is an upper bound; no move improved alpha
+	Upper,
+}
+
+/// A cached search result for a previously explored position
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Entry {
+	/// The remaining depth the position was searched to
+	pub depth: u32,
+	/// The score recorded for the position
+	pub score: i32,
+	/// How `score` relates to the position's true value
+	pub bound: Bound,
+}
+
+/// Caches search results keyed on a position's packed byte encoding, so a
+/// re-search of the same node at an equal or shallower depth can reuse or
+/// tighten a previous result instead of re-exploring it
+#[derive(Clone, Debug, Default)]
+pub struct TranspositionTable {
+	entries: HashMap<Vec<u8>, Entry>,
+}
+
+impl TranspositionTable {
+	/// Creates an empty transposition table
+	pub fn new() -> Self {
+		TranspositionTable {
+			entries: HashMap::new(),
+		}
+	}
+
+	/// Looks up the entry stored for `key`, if any
+	pub fn get(&self, key: &[u8]) -> Option<&Entry> {
+		self.entries.get(key)
+	}
+
+	/// Stores `entry` for `key`, overwriting whatever was previously stored
+	/// there
+	pub fn insert(&mut self, key: Vec<u8>, entry: Entry) {
+		self.entries.insert(key, entry);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn new_is_empty() {
+		let cut = TranspositionTable::new();
+		assert_eq!(cut.get(&[1, 2, 3]), None);
+	}
+
+	#[test]
+	fn insert_then_get() {
+		let mut cut = TranspositionTable::new();
+		let entry = Entry {
+			depth: 4,
+			score: 12,
+			bound: Bound::Exact,
+		};
+		cut.insert(vec![1, 2, 3], entry);
+		assert_eq!(cut.get(&[1, 2, 3]), Some(&entry));
+	}
+
+	#[test]
+	fn insert_overwrites_previous_entry() {
+		let mut cut = TranspositionTable::new();
+		cut.insert(
+			vec![1],
+			Entry {
+				depth: 2,
+				score: 1,
+				bound: Bound::Lower,
+			},
+		);
+		let entry = Entry {
+			depth: 5,
+			score: 9,
+			bound: Bound::Upper,
+		};
+		cut.insert(vec![1], entry);
+		assert_eq!(cut.get(&[1]), Some(&entry));
+	}
+}